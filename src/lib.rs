@@ -23,21 +23,120 @@ pub struct Season {
 }
 
 /// A football game.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Game {
     date: NaiveDate,
     matchweek: u16,
     home: String,
     away: String,
     scores: Option<Scores>,
+    status: Status,
+    stage: Option<Stage>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 struct Scores {
     home: u16,
     away: u16,
 }
 
+/// The status of a game.
+///
+/// This mirrors the status values used by feeds like football-data.org, so callers can filter
+/// fixtures the same way regardless of where the underlying data came from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum Status {
+    /// The game has been scheduled, but no kickoff time is known yet.
+    Scheduled,
+    /// The game has a known kickoff time but hasn't started.
+    Timed,
+    /// The game is currently being played.
+    InPlay,
+    /// The game has been played to completion, and its score is final.
+    Finished,
+    /// The game was postponed and has not yet been rescheduled.
+    Postponed,
+}
+
+/// The stage of a competition a game belongs to.
+///
+/// Openfootball's league files only have numbered matchdays, but cup and continental
+/// competition files use round headers like "Qualifying Round", "Group A", "Round of 16", or
+/// "Final" instead. A `Stage` captures both the broad `kind` of round (useful for grouping
+/// games into a league-style group table vs. a knockout bracket) and the `round` name as it
+/// appeared in the file (useful for display and for telling rounds of the same kind apart,
+/// e.g. "Group A" vs. "Group B").
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Stage {
+    kind: StageKind,
+    round: String,
+}
+
+impl Stage {
+    /// Parses a round header line into a `Stage`, or returns `None` if the line doesn't look
+    /// like one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Stage, StageKind};
+    /// let stage = Stage::parse("Round of 16").unwrap();
+    /// assert_eq!(StageKind::Knockout, stage.kind());
+    /// assert_eq!("Round of 16", stage.round());
+    /// assert!(Stage::parse("Matchday 7").is_none());
+    /// ```
+    pub fn parse(line: &str) -> Option<Stage> {
+        let lower = line.to_lowercase();
+        // Cup/continental files spell some round headers with a hyphen (e.g. "Quarter-finals"),
+        // so match against a hyphen-stripped copy rather than requiring one fixed spelling.
+        let normalized = lower.replace('-', "");
+        let kind = if lower == "final" {
+            StageKind::Final
+        } else if normalized.contains("round of")
+            || normalized.contains("quarterfinal")
+            || normalized.contains("semifinal")
+        {
+            StageKind::Knockout
+        } else if normalized.contains("group") {
+            StageKind::Group
+        } else if normalized.contains("qualif")
+            || normalized.contains("preliminary")
+            || normalized.contains("playoff")
+        {
+            StageKind::Qualifying
+        } else {
+            return None;
+        };
+        Some(Stage {
+            kind: kind,
+            round: line.to_string(),
+        })
+    }
+
+    /// Returns this stage's broad kind.
+    pub fn kind(&self) -> StageKind {
+        self.kind
+    }
+
+    /// Returns this stage's round name, as it appeared in the season file.
+    pub fn round(&self) -> &str {
+        &self.round
+    }
+}
+
+/// The broad kind of a competition `Stage`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum StageKind {
+    /// A round played to qualify for the main competition.
+    Qualifying,
+    /// A round-robin group, e.g. "Group A".
+    Group,
+    /// A single- or two-legged knockout round, e.g. "Round of 16" or "Quarterfinals".
+    Knockout,
+    /// The final.
+    Final,
+}
+
 /// A team's standing at the end of a day.
 #[derive(Debug, Serialize)]
 pub struct Standing {
@@ -49,6 +148,11 @@ pub struct Standing {
     losses: u16,
     goals_for: u16,
     goals_against: u16,
+    goal_difference: i32,
+    points: u32,
+    /// This team's rank in the table, as computed by `Season::table`. Zero for standings
+    /// produced by `Season::standings`, which aren't ranked against the rest of the league.
+    position: u16,
     elo_rating: i32,
 }
 
@@ -72,6 +176,18 @@ pub struct Odds {
     away_expected_score: f64,
 }
 
+/// A Poisson-based 1X2 prediction for an upcoming game.
+#[derive(Debug, Serialize)]
+pub struct Prediction {
+    home: String,
+    away: String,
+    home_win_probability: f64,
+    draw_probability: f64,
+    away_win_probability: f64,
+    predicted_home_score: u16,
+    predicted_away_score: u16,
+}
+
 /// Crate-specific errors.
 #[derive(Debug, Fail)]
 pub enum Error {
@@ -87,6 +203,10 @@ pub enum Error {
 impl Season {
     /// Reads a season from a path on the filesystem.
     ///
+    /// Team names are taken verbatim from the file. If you're merging data from multiple
+    /// sources and want a single standings row per club, use
+    /// [`from_path_with_aliases`](Season::from_path_with_aliases) instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -94,6 +214,27 @@ impl Season {
     /// let season = Season::from_path("tests/data/pl.txt").unwrap();
     /// ```
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Season, failure::Error> {
+        Season::from_path_with_aliases(path, &HashMap::new())
+    }
+
+    /// Reads a season from a path on the filesystem, rewriting team names through an alias map.
+    ///
+    /// `aliases` maps a spelling as it appears in the text file (e.g. "Vitória SC") to the
+    /// canonical name that should be used instead (e.g. "Vitória Guimarães"). This lets data
+    /// pulled from different openfootball datasets, where the same club is spelled differently,
+    /// collapse into one standings row per club. Use [`default_aliases`] for a built-in table of
+    /// known spelling variants, or build your own for a particular league.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Season;
+    /// let season = Season::from_path_with_aliases("tests/data/pl.txt", &Default::default()).unwrap();
+    /// ```
+    pub fn from_path_with_aliases<P: AsRef<Path>>(
+        path: P,
+        aliases: &HashMap<String, String>,
+    ) -> Result<Season, failure::Error> {
         use chrono::{Datelike, NaiveDate, Utc};
         use regex::Regex;
         use std::fs::File;
@@ -103,6 +244,7 @@ impl Season {
         let header_regex = Regex::new(r"^# (?P<name>.+) (?P<year>\d{4})/\d{2}$").unwrap();
         let mut matchweek = 0u16;
         let matchday_regex = Regex::new(r"^Matchday (?P<matchweek>\d+)$").unwrap();
+        let mut stage = None;
         let mut date = Utc::today().naive_utc();
         let date_regex =
             Regex::new(r"^\[[[:alpha:]]{3} (?P<month>[[:alpha:]]{3})/(?P<day>\d+)\]$").unwrap();
@@ -116,7 +258,7 @@ impl Season {
             (?P<away_score>\d+)?
             \s+
             (?P<away>.+?)
-            (\s*postponed)?
+            (?P<postponed>\s*postponed)?
             $
         ",
         )
@@ -132,6 +274,8 @@ impl Season {
                 year = captures.name("year").unwrap().as_str().parse()?;
             } else if let Some(captures) = matchday_regex.captures(line) {
                 matchweek = captures.name("matchweek").unwrap().as_str().parse()?;
+            } else if let Some(parsed_stage) = Stage::parse(line) {
+                stage = Some(parsed_stage);
             } else if let Some(captures) = date_regex.captures(line) {
                 date = NaiveDate::parse_from_str(
                     &format!(
@@ -146,9 +290,10 @@ impl Season {
                     date = date.with_year(year + 1).unwrap();
                 }
             } else if let Some(captures) = game_regex.captures(line) {
-                let home = captures.name("home").unwrap().as_str();
-                let away = captures.name("away").unwrap().as_str();
-                let mut game = Game::new(matchweek, date, home, away);
+                let home = canonical_name(captures.name("home").unwrap().as_str(), aliases);
+                let away = canonical_name(captures.name("away").unwrap().as_str(), aliases);
+                let mut game = Game::new(matchweek, date, &home, &away);
+                game.stage = stage.clone();
                 if let Some((home_score, away_score)) = captures
                     .name("home_score")
                     .and_then(|h| captures.name("away_score").map(|a| (h, a)))
@@ -156,6 +301,8 @@ impl Season {
                     let home_score = home_score.as_str().parse::<u16>()?;
                     let away_score = away_score.as_str().parse::<u16>()?;
                     game.set_scores(home_score, away_score);
+                } else if captures.name("postponed").is_some() {
+                    game.status = Status::Postponed;
                 }
                 games.push(game);
             } else {
@@ -166,7 +313,7 @@ impl Season {
         Ok(Season { games: games })
     }
 
-    /// Returns this season's played games as a slice.
+    /// Returns all of this season's games as a slice, played and unplayed alike.
     ///
     /// # Examples
     ///
@@ -179,22 +326,123 @@ impl Season {
         &self.games
     }
 
-    /// Returns this season's standings.
+    /// Returns this season's played games, i.e. those with `Status::Finished`.
     ///
-    /// These are calculated from all played games.
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Season;
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let played = season.played();
+    /// ```
+    pub fn played(&self) -> Vec<&Game> {
+        self.games
+            .iter()
+            .filter(|game| game.status == Status::Finished)
+            .collect()
+    }
+
+    /// Returns this season's unplayed fixtures, in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Season;
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let upcoming = season.upcoming();
+    /// ```
+    pub fn upcoming(&self) -> Vec<&Game> {
+        let mut upcoming: Vec<&Game> = self
+            .games
+            .iter()
+            .filter(|game| game.status != Status::Finished)
+            .collect();
+        upcoming.sort_by_key(|game| (game.date, game.matchweek));
+        upcoming
+    }
+
+    /// Returns the matchweek of the next unplayed fixture, or `None` if the season is complete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Season;
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let next_matchweek = season.next_matchweek();
+    /// ```
+    pub fn next_matchweek(&self) -> Option<u16> {
+        self.upcoming().first().map(|game| game.matchweek)
+    }
+
+    /// Groups this season's games by stage round name.
+    ///
+    /// Games with no stage (e.g. regular league matchdays) are omitted. This is meant for cup
+    /// and continental competition files, where group-stage games should be tabled per group
+    /// and knockout rounds reported as a bracket rather than a single season-wide table.
     ///
     /// # Examples
     ///
     /// ```
     /// use openfootball::Season;
     /// let season = Season::from_path("tests/data/pl.txt").unwrap();
-    /// let standings = season.standings(1500, 32.);
+    /// let by_stage = season.by_stage();
+    /// assert!(by_stage.is_empty());
     /// ```
-    pub fn standings(&self, initial_elo_rating: i32, k: f64) -> Result<Vec<Standing>, Error> {
+    pub fn by_stage(&self) -> HashMap<&str, Vec<&Game>> {
+        let mut by_stage: HashMap<&str, Vec<&Game>> = HashMap::new();
+        for game in &self.games {
+            if let Some(stage) = &game.stage {
+                by_stage.entry(stage.round()).or_default().push(game);
+            }
+        }
+        by_stage
+    }
+
+    /// Returns this season's standings.
+    ///
+    /// These are calculated from all played games, using 3 points for a win and 1 for a draw.
+    /// Use [`standings_with_points`](Season::standings_with_points) for competitions that used a
+    /// different points system (e.g. 2 points for a win, before the 1981 switch to 3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Season, DEFAULT_HOME_ADVANTAGE};
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE);
+    /// ```
+    pub fn standings(
+        &self,
+        initial_elo_rating: i32,
+        k: f64,
+        home_advantage: f64,
+    ) -> Result<Vec<Standing>, Error> {
+        self.standings_with_points(initial_elo_rating, k, home_advantage, 3, 1)
+    }
+
+    /// Returns this season's standings, using a configurable points-per-win and points-per-draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Season, DEFAULT_HOME_ADVANTAGE};
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let standings = season.standings_with_points(1500, 32., DEFAULT_HOME_ADVANTAGE, 2, 1);
+    /// ```
+    pub fn standings_with_points(
+        &self,
+        initial_elo_rating: i32,
+        k: f64,
+        home_advantage: f64,
+        win_points: u32,
+        draw_points: u32,
+    ) -> Result<Vec<Standing>, Error> {
         let mut stats = self.stats(initial_elo_rating);
         let mut standings = Vec::new();
         for game in &self.games {
-            if let Some((home, away)) = game.update_stats(&mut stats, k)? {
+            if let Some((home, away)) =
+                game.update_stats(&mut stats, k, home_advantage, win_points, draw_points)?
+            {
                 standings.push(home);
                 standings.push(away);
             }
@@ -207,19 +455,20 @@ impl Season {
     /// # Examples
     ///
     /// ```
-    /// use openfootball::Season;
+    /// use openfootball::{Season, DEFAULT_HOME_ADVANTAGE};
     /// let season = Season::from_path("tests/data/pl.txt").unwrap();
-    /// let odds = season.odds(1500, 32., 29);
+    /// let odds = season.odds(1500, 32., DEFAULT_HOME_ADVANTAGE, 29);
     /// ```
     pub fn odds(
         &self,
         initial_elo_rating: i32,
         k: f64,
+        home_advantage: f64,
         matchweek: u16,
     ) -> Result<Vec<Odds>, Error> {
         let mut stats = self.stats(initial_elo_rating);
         for game in self.games.iter().filter(|game| game.matchweek < matchweek) {
-            game.update_stats(&mut stats, k)?;
+            game.update_stats(&mut stats, k, home_advantage, 3, 1)?;
         }
         self.games
             .iter()
@@ -233,6 +482,100 @@ impl Season {
             .collect()
     }
 
+    /// Returns the league table as of a given matchweek, ranked by points, then goal
+    /// difference, then goals scored, then head-to-head record between tied teams.
+    ///
+    /// Uses 3 points for a win and 1 for a draw; see
+    /// [`table_with_points`](Season::table_with_points) for other eras' points systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Season, DEFAULT_HOME_ADVANTAGE};
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let table = season.table(29, 1500, 32., DEFAULT_HOME_ADVANTAGE);
+    /// ```
+    pub fn table(
+        &self,
+        matchweek: u16,
+        initial_elo_rating: i32,
+        k: f64,
+        home_advantage: f64,
+    ) -> Result<Vec<Standing>, Error> {
+        self.table_with_points(matchweek, initial_elo_rating, k, home_advantage, 3, 1)
+    }
+
+    /// Returns the league table as of a given matchweek, using a configurable points-per-win
+    /// and points-per-draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Season, DEFAULT_HOME_ADVANTAGE};
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let table = season.table_with_points(29, 1500, 32., DEFAULT_HOME_ADVANTAGE, 2, 1);
+    /// ```
+    pub fn table_with_points(
+        &self,
+        matchweek: u16,
+        initial_elo_rating: i32,
+        k: f64,
+        home_advantage: f64,
+        win_points: u32,
+        draw_points: u32,
+    ) -> Result<Vec<Standing>, Error> {
+        let mut stats = self.stats(initial_elo_rating);
+        let mut latest: HashMap<String, Standing> = HashMap::new();
+        let mut played = Vec::new();
+        for game in self.games.iter().filter(|game| game.matchweek <= matchweek) {
+            if let Some((home, away)) =
+                game.update_stats(&mut stats, k, home_advantage, win_points, draw_points)?
+            {
+                latest.insert(home.team.clone(), home);
+                latest.insert(away.team.clone(), away);
+                played.push(game);
+            }
+        }
+        let mut table: Vec<Standing> = latest.into_values().collect();
+        table.sort_by(|a, b| {
+            b.points
+                .cmp(&a.points)
+                .then_with(|| b.goal_difference.cmp(&a.goal_difference))
+                .then_with(|| b.goals_for.cmp(&a.goals_for))
+                .then_with(|| head_to_head(&played, &a.team, &b.team, win_points, draw_points))
+        });
+        for (position, standing) in table.iter_mut().enumerate() {
+            standing.position = (position + 1) as u16;
+        }
+        Ok(table)
+    }
+
+    /// Returns Poisson-based predictions for every upcoming game in a given matchweek.
+    ///
+    /// Each team's attack and defense strength, and the league's home-advantage factor, are
+    /// estimated from every game played before the given matchweek. Expected goals for each side
+    /// feed independent Poisson distributions over scorelines of up to 10 goals a side, from which
+    /// the home-win, draw, and away-win probabilities and the most likely scoreline are taken.
+    /// Teams that haven't played any earlier games fall back to league-average strength.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Season;
+    /// let season = Season::from_path("tests/data/pl.txt").unwrap();
+    /// let predictions = season.predictions(29);
+    /// ```
+    pub fn predictions(&self, matchweek: u16) -> Vec<Prediction> {
+        let strengths = TeamStrengths::from_games(
+            self.games.iter().filter(|game| game.matchweek < matchweek),
+        );
+        self.games
+            .iter()
+            .filter(|game| game.matchweek == matchweek && game.status != Status::Finished)
+            .map(|game| Prediction::new(game, &strengths))
+            .collect()
+    }
+
     fn stats(&self, initial_elo_rating: i32) -> HashMap<String, Stats> {
         use std::collections::HashSet;
         let mut teams = HashSet::new();
@@ -263,23 +606,54 @@ impl Game {
             home: home.to_string(),
             away: away.to_string(),
             scores: None,
+            status: Status::Scheduled,
+            stage: None,
         }
     }
 
-    /// Sets the scores for a game.
+    /// Sets the scores for a game, marking it as `Status::Finished`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use openfootball::Game;
+    /// use openfootball::{Game, Status};
     /// let mut game = Game::new(1, "2018-08-11".parse().unwrap(), "Newcastle United", "Tottenham Hotspur");
     /// game.set_scores(1, 2);
+    /// assert_eq!(Status::Finished, game.status());
     /// ```
     pub fn set_scores(&mut self, home: u16, away: u16) {
         self.scores = Some(Scores {
             home: home,
             away: away,
         });
+        self.status = Status::Finished;
+    }
+
+    /// Returns this game's status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::{Game, Status};
+    /// let game = Game::new(1, "2018-08-11".parse().unwrap(), "Newcastle United", "Tottenham Hotspur");
+    /// assert_eq!(Status::Scheduled, game.status());
+    /// ```
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns this game's stage, if it was parsed from a cup or continental competition round
+    /// header rather than a numbered matchday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Game;
+    /// let game = Game::new(1, "2018-08-11".parse().unwrap(), "Newcastle United", "Tottenham Hotspur");
+    /// assert!(game.stage().is_none());
+    /// ```
+    pub fn stage(&self) -> Option<&Stage> {
+        self.stage.as_ref()
     }
 
     /// Returns the home team's name.
@@ -308,11 +682,43 @@ impl Game {
         &self.away
     }
 
+    /// Returns this game's matchweek.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Game;
+    /// let game = Game::new(1, "2018-08-11".parse().unwrap(), "Newcastle United", "Tottenham Hotspur");
+    /// assert_eq!(1, game.matchweek());
+    /// ```
+    pub fn matchweek(&self) -> u16 {
+        self.matchweek
+    }
+
+    /// Returns this game's date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openfootball::Game;
+    /// let game = Game::new(1, "2018-08-11".parse().unwrap(), "Newcastle United", "Tottenham Hotspur");
+    /// assert_eq!("2018-08-11", game.date().to_string());
+    /// ```
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
     fn update_stats(
         &self,
         stats: &mut HashMap<String, Stats>,
         k: f64,
+        home_advantage: f64,
+        win_points: u32,
+        draw_points: u32,
     ) -> Result<Option<(Standing, Standing)>, Error> {
+        if self.status != Status::Finished {
+            return Ok(None);
+        }
         let scores = if let Some(scores) = &self.scores {
             scores
         } else {
@@ -330,6 +736,20 @@ impl Game {
                 .ok_or(Error::MissingTeam(self.away.to_string()))?
                 .elo_rating,
         );
+        let adjusted_home_rating = home_rating + home_advantage;
+        let goal_difference = i32::from(scores.home) - i32::from(scores.away);
+        let g = if goal_difference == 0 {
+            // A draw still carries Elo-moving information (e.g. a big favorite held level), so
+            // the margin-of-victory multiplier is neutral here rather than zeroing the update.
+            1.
+        } else if goal_difference > 0 {
+            margin_of_victory_multiplier(goal_difference.unsigned_abs() as u16, home_rating - away_rating)
+        } else {
+            margin_of_victory_multiplier(
+                goal_difference.unsigned_abs() as u16,
+                away_rating - adjusted_home_rating,
+            )
+        };
         let mut update = |team: &str,
                           goals_for: u16,
                           goals_against: u16,
@@ -350,7 +770,9 @@ impl Game {
                 stats.draws += 1;
                 0.5
             };
-            stats.elo_rating += (k * (actual - expected)).round() as i32;
+            stats.elo_rating += (k * g * (actual - expected)).round() as i32;
+            let points = stats.wins as u32 * win_points + stats.draws as u32 * draw_points;
+            let goal_difference = stats.goals_for as i32 - stats.goals_against as i32;
 
             Ok(Standing {
                 matchweek: self.matchweek,
@@ -361,16 +783,82 @@ impl Game {
                 losses: stats.losses,
                 goals_for: stats.goals_for,
                 goals_against: stats.goals_against,
+                goal_difference: goal_difference,
+                points: points,
+                position: 0,
                 elo_rating: stats.elo_rating,
             })
         };
-        let (expected_home, expected_away) = expected_score(home_rating, away_rating);
+        let (expected_home, expected_away) = expected_score(adjusted_home_rating, away_rating);
         let home = update(&self.home, scores.home, scores.away, expected_home)?;
         let away = update(&self.away, scores.away, scores.home, expected_away)?;
         Ok(Some((home, away)))
     }
 }
 
+impl Standing {
+    /// Returns this standing's team.
+    pub fn team(&self) -> &str {
+        &self.team
+    }
+
+    /// Returns the matchweek this standing was calculated at.
+    pub fn matchweek(&self) -> u16 {
+        self.matchweek
+    }
+
+    /// Returns the number of wins.
+    pub fn wins(&self) -> u16 {
+        self.wins
+    }
+
+    /// Returns the number of draws.
+    pub fn draws(&self) -> u16 {
+        self.draws
+    }
+
+    /// Returns the number of losses.
+    pub fn losses(&self) -> u16 {
+        self.losses
+    }
+
+    /// Returns the number of games played.
+    pub fn played(&self) -> u16 {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Returns the number of goals scored.
+    pub fn goals_for(&self) -> u16 {
+        self.goals_for
+    }
+
+    /// Returns the number of goals conceded.
+    pub fn goals_against(&self) -> u16 {
+        self.goals_against
+    }
+
+    /// Returns the goal difference (goals for minus goals against).
+    pub fn goal_difference(&self) -> i32 {
+        self.goal_difference
+    }
+
+    /// Returns the competition points.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns this team's rank in the table, or zero if this standing wasn't produced by
+    /// `Season::table`.
+    pub fn position(&self) -> u16 {
+        self.position
+    }
+
+    /// Returns this team's Elo rating.
+    pub fn elo_rating(&self) -> i32 {
+        self.elo_rating
+    }
+}
+
 impl Stats {
     fn new(initial_elo_rating: i32) -> Stats {
         Stats {
@@ -400,6 +888,221 @@ impl Odds {
             away_expected_score: away,
         })
     }
+
+    /// Returns the home team.
+    pub fn home(&self) -> &str {
+        &self.home
+    }
+
+    /// Returns the home team's expected score, in [0, 1].
+    pub fn home_expected_score(&self) -> f64 {
+        self.home_expected_score
+    }
+
+    /// Returns the away team.
+    pub fn away(&self) -> &str {
+        &self.away
+    }
+
+    /// Returns the away team's expected score, in [0, 1].
+    pub fn away_expected_score(&self) -> f64 {
+        self.away_expected_score
+    }
+}
+
+impl Prediction {
+    fn new(game: &Game, strengths: &TeamStrengths) -> Prediction {
+        let home = strengths.get(&game.home);
+        let away = strengths.get(&game.away);
+        let lambda_home = home.attack * away.defense * strengths.mu * strengths.home_advantage.sqrt();
+        let lambda_away = away.attack * home.defense * strengths.mu / strengths.home_advantage.sqrt();
+
+        const MAX_GOALS: u16 = 10;
+        let mut home_win_probability = 0.;
+        let mut draw_probability = 0.;
+        let mut away_win_probability = 0.;
+        let mut best_score = (0u16, 0u16);
+        let mut best_probability = -1.;
+        for i in 0..=MAX_GOALS {
+            for j in 0..=MAX_GOALS {
+                let probability = poisson_pmf(lambda_home, i) * poisson_pmf(lambda_away, j);
+                if i > j {
+                    home_win_probability += probability;
+                } else if i == j {
+                    draw_probability += probability;
+                } else {
+                    away_win_probability += probability;
+                }
+                if probability > best_probability {
+                    best_probability = probability;
+                    best_score = (i, j);
+                }
+            }
+        }
+
+        Prediction {
+            home: game.home.clone(),
+            away: game.away.clone(),
+            home_win_probability: home_win_probability,
+            draw_probability: draw_probability,
+            away_win_probability: away_win_probability,
+            predicted_home_score: best_score.0,
+            predicted_away_score: best_score.1,
+        }
+    }
+
+    /// Returns the home team.
+    pub fn home(&self) -> &str {
+        &self.home
+    }
+
+    /// Returns the away team.
+    pub fn away(&self) -> &str {
+        &self.away
+    }
+
+    /// Returns the probability of a home win, in [0, 1].
+    pub fn home_win_probability(&self) -> f64 {
+        self.home_win_probability
+    }
+
+    /// Returns the probability of a draw, in [0, 1].
+    pub fn draw_probability(&self) -> f64 {
+        self.draw_probability
+    }
+
+    /// Returns the probability of an away win, in [0, 1].
+    pub fn away_win_probability(&self) -> f64 {
+        self.away_win_probability
+    }
+
+    /// Returns the most likely scoreline.
+    pub fn predicted_score(&self) -> (u16, u16) {
+        (self.predicted_home_score, self.predicted_away_score)
+    }
+}
+
+/// A team's estimated attack and defense strength, relative to the league average.
+struct Strength {
+    attack: f64,
+    defense: f64,
+}
+
+/// Per-team attack/defense strengths and the league's home-advantage factor, estimated from
+/// the played games it is given. Used to build `Prediction`s via an independent-Poisson model.
+struct TeamStrengths {
+    strengths: HashMap<String, Strength>,
+    mu: f64,
+    home_advantage: f64,
+}
+
+impl TeamStrengths {
+    fn from_games<'a>(games: impl Iterator<Item = &'a Game>) -> TeamStrengths {
+        let mut goals_for: HashMap<String, u32> = HashMap::new();
+        let mut goals_against: HashMap<String, u32> = HashMap::new();
+        let mut games_played: HashMap<String, u32> = HashMap::new();
+        let mut total_home_goals = 0u32;
+        let mut total_away_goals = 0u32;
+        let mut played_games = 0u32;
+
+        for game in games {
+            let scores = if game.status == Status::Finished {
+                match &game.scores {
+                    Some(scores) => scores,
+                    None => continue,
+                }
+            } else {
+                continue;
+            };
+            *goals_for.entry(game.home.clone()).or_insert(0) += u32::from(scores.home);
+            *goals_against.entry(game.home.clone()).or_insert(0) += u32::from(scores.away);
+            *games_played.entry(game.home.clone()).or_insert(0) += 1;
+            *goals_for.entry(game.away.clone()).or_insert(0) += u32::from(scores.away);
+            *goals_against.entry(game.away.clone()).or_insert(0) += u32::from(scores.home);
+            *games_played.entry(game.away.clone()).or_insert(0) += 1;
+            total_home_goals += u32::from(scores.home);
+            total_away_goals += u32::from(scores.away);
+            played_games += 1;
+        }
+
+        let mu = if played_games > 0 {
+            f64::from(total_home_goals + total_away_goals) / f64::from(2 * played_games)
+        } else {
+            1.
+        };
+        let home_advantage = if total_home_goals > 0 && total_away_goals > 0 {
+            f64::from(total_home_goals) / f64::from(total_away_goals)
+        } else {
+            1.
+        };
+
+        let mut strengths = HashMap::new();
+        for team in games_played.keys() {
+            let played = f64::from(games_played[team]);
+            let attack = if mu > 0. {
+                (f64::from(goals_for[team]) / played) / mu
+            } else {
+                1.
+            };
+            let defense = if mu > 0. {
+                (f64::from(goals_against[team]) / played) / mu
+            } else {
+                1.
+            };
+            strengths.insert(team.clone(), Strength { attack: attack, defense: defense });
+        }
+
+        TeamStrengths {
+            strengths: strengths,
+            mu: mu,
+            home_advantage: home_advantage,
+        }
+    }
+
+    fn get(&self, team: &str) -> Strength {
+        match self.strengths.get(team) {
+            Some(strength) => Strength {
+                attack: strength.attack,
+                defense: strength.defense,
+            },
+            None => Strength {
+                attack: 1.,
+                defense: 1.,
+            },
+        }
+    }
+}
+
+fn poisson_pmf(lambda: f64, n: u16) -> f64 {
+    let mut factorial = 1f64;
+    for i in 1..=n {
+        factorial *= f64::from(i);
+    }
+    (-lambda).exp() * lambda.powi(i32::from(n)) / factorial
+}
+
+/// Returns a built-in alias table for club names that are spelled differently across datasets.
+///
+/// This is not exhaustive — it's a starting point covering a handful of clubs that are known to
+/// appear under multiple spellings in openfootball data. Merge it with your own entries as you
+/// find more.
+///
+/// # Examples
+///
+/// ```
+/// use openfootball::default_aliases;
+/// let aliases = default_aliases();
+/// assert_eq!("Vitória Guimarães", aliases["Vitória SC"]);
+/// ```
+pub fn default_aliases() -> HashMap<String, String> {
+    let mut aliases = HashMap::new();
+    aliases.insert("Vitória SC".to_string(), "Vitória Guimarães".to_string());
+    aliases.insert("América FC".to_string(), "América Mineiro".to_string());
+    aliases
+}
+
+fn canonical_name(name: &str, aliases: &HashMap<String, String>) -> String {
+    aliases.get(name).cloned().unwrap_or_else(|| name.to_string())
 }
 
 fn expected_score(home: f64, away: f64) -> (f64, f64) {
@@ -408,6 +1111,57 @@ fn expected_score(home: f64, away: f64) -> (f64, f64) {
     (home, away)
 }
 
+/// The typical Elo boost given to a home team, absent any better estimate.
+///
+/// This is in the same spirit as FiveThirtyEight's NFL Elo model, which uses a home-field
+/// advantage of about 65 points.
+pub const DEFAULT_HOME_ADVANTAGE: f64 = 65.;
+
+/// Scales an Elo rating change by how lopsided the result was.
+///
+/// `goal_difference` is the absolute margin of victory, and `rating_difference` is the
+/// winner's pre-game rating minus the loser's (home-advantage-adjusted, if applicable) rating,
+/// so that running up the score against a much stronger opponent is dampened.
+fn margin_of_victory_multiplier(goal_difference: u16, rating_difference: f64) -> f64 {
+    (f64::from(goal_difference) + 1.).ln() * (2.2 / (0.001 * rating_difference + 2.2))
+}
+
+/// Breaks a tie between two teams by the points they took off each other in played games.
+fn head_to_head(
+    games: &[&Game],
+    a: &str,
+    b: &str,
+    win_points: u32,
+    draw_points: u32,
+) -> std::cmp::Ordering {
+    let mut a_points = 0u32;
+    let mut b_points = 0u32;
+    for game in games {
+        let (a_score, b_score) = if game.home == a && game.away == b {
+            match &game.scores {
+                Some(scores) => (scores.home, scores.away),
+                None => continue,
+            }
+        } else if game.home == b && game.away == a {
+            match &game.scores {
+                Some(scores) => (scores.away, scores.home),
+                None => continue,
+            }
+        } else {
+            continue;
+        };
+        if a_score > b_score {
+            a_points += win_points;
+        } else if a_score < b_score {
+            b_points += win_points;
+        } else {
+            a_points += draw_points;
+            b_points += draw_points;
+        }
+    }
+    b_points.cmp(&a_points)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -418,10 +1172,238 @@ mod tests {
         assert_eq!(380, season.games().len());
     }
 
+    #[test]
+    fn from_path_with_aliases() {
+        let path = std::env::temp_dir().join("openfootball_from_path_with_aliases.txt");
+        std::fs::write(
+            &path,
+            "# Premier League 2018/19\n\
+             Matchday 1\n\
+             [Sat Aug/11]\n\
+             Manchester Utd 2-1 Leicester City\n",
+        )
+        .unwrap();
+
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "Manchester Utd".to_string(),
+            "Manchester United".to_string(),
+        );
+        let season = Season::from_path_with_aliases(&path, &aliases).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(1, season.games().len());
+        let game = &season.games()[0];
+        assert_eq!("Manchester United", game.home());
+        assert_eq!("Leicester City", game.away());
+    }
+
+    #[test]
+    fn predictions() {
+        let date = "2018-08-11".parse().unwrap();
+        let mut a_beats_b = Game::new(1, date, "A", "B");
+        a_beats_b.set_scores(2, 1);
+        let mut a_beats_b_again = Game::new(2, date, "B", "A");
+        a_beats_b_again.set_scores(1, 2);
+        let upcoming = Game::new(3, date, "A", "B");
+        let season = Season {
+            games: vec![a_beats_b, a_beats_b_again, upcoming],
+        };
+
+        let predictions = season.predictions(3);
+        assert_eq!(1, predictions.len());
+        let prediction = &predictions[0];
+        assert_eq!("A", prediction.home());
+        assert_eq!("B", prediction.away());
+        let total = prediction.home_win_probability()
+            + prediction.draw_probability()
+            + prediction.away_win_probability();
+        assert!((total - 1.).abs() < 1e-3);
+        assert!(prediction.home_win_probability() > prediction.away_win_probability());
+    }
+
+    #[test]
+    fn predictions_ignore_future_results() {
+        let date = "2018-08-11".parse().unwrap();
+        let upcoming = Game::new(0, date, "A", "B");
+        let mut a_routs_b_later = Game::new(1, date, "A", "B");
+        a_routs_b_later.set_scores(5, 0);
+        let season = Season {
+            games: vec![upcoming, a_routs_b_later],
+        };
+
+        let predictions = season.predictions(0);
+        assert_eq!(1, predictions.len());
+        let prediction = &predictions[0];
+        // With no earlier games to draw on, both teams fall back to league-average strength,
+        // which predicts a scoreless draw rather than being skewed by A's future 5-0 rout.
+        assert_eq!((0, 0), prediction.predicted_score());
+    }
+
+    #[test]
+    fn predictions_handle_shutout_home_advantage() {
+        let date = "2018-08-11".parse().unwrap();
+        let mut b_shuts_out_a = Game::new(1, date, "A", "B");
+        b_shuts_out_a.set_scores(0, 1);
+        let upcoming = Game::new(2, date, "A", "B");
+        let season = Season {
+            games: vec![b_shuts_out_a, upcoming],
+        };
+
+        let predictions = season.predictions(2);
+        assert_eq!(1, predictions.len());
+        let prediction = &predictions[0];
+        let total = prediction.home_win_probability()
+            + prediction.draw_probability()
+            + prediction.away_win_probability();
+        assert!(total.is_finite());
+        assert!((total - 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn table() {
+        let date = "2018-08-11".parse().unwrap();
+        let mut a_beats_b = Game::new(1, date, "A", "B");
+        a_beats_b.set_scores(3, 0);
+        let mut b_beats_c = Game::new(2, date, "B", "C");
+        b_beats_c.set_scores(1, 0);
+        let mut a_draws_c = Game::new(2, date, "A", "C");
+        a_draws_c.set_scores(1, 1);
+        let season = Season {
+            games: vec![a_beats_b, b_beats_c, a_draws_c],
+        };
+
+        let table = season.table(2, 1500, 32., DEFAULT_HOME_ADVANTAGE).unwrap();
+        assert_eq!(3, table.len());
+        assert_eq!("A", table[0].team);
+        assert_eq!(4, table[0].points);
+        assert_eq!(3, table[0].goal_difference);
+        assert_eq!(1, table[0].position);
+        assert_eq!("B", table[1].team);
+        assert_eq!(3, table[1].points);
+        assert_eq!("C", table[2].team);
+        assert_eq!(1, table[2].points);
+        assert_eq!(3, table[2].position);
+    }
+
+    #[test]
+    fn elo_margin_of_victory() {
+        let date = "2018-08-11".parse().unwrap();
+        let mut a_beats_b_big = Game::new(1, date, "A", "B");
+        a_beats_b_big.set_scores(4, 0);
+        let season = Season {
+            games: vec![a_beats_b_big],
+        };
+        let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE).unwrap();
+        let a = standings.iter().find(|s| s.team == "A").unwrap();
+
+        let mut a_beats_b_narrow = Game::new(1, date, "A", "B");
+        a_beats_b_narrow.set_scores(1, 0);
+        let season = Season {
+            games: vec![a_beats_b_narrow],
+        };
+        let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE).unwrap();
+        let narrow_a = standings.iter().find(|s| s.team == "A").unwrap();
+
+        assert!(a.elo_rating > narrow_a.elo_rating);
+
+        let no_home_advantage_standings = season.standings(1500, 32., 0.).unwrap();
+        let no_home_advantage_a = no_home_advantage_standings
+            .iter()
+            .find(|s| s.team == "A")
+            .unwrap();
+        assert!(no_home_advantage_a.elo_rating > narrow_a.elo_rating);
+    }
+
+    #[test]
+    fn elo_draw_still_moves_rating() {
+        let mut draw = Game::new(1, "2018-08-11".parse().unwrap(), "A", "B");
+        draw.set_scores(1, 1);
+        let season = Season { games: vec![draw] };
+
+        let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE).unwrap();
+        let a = standings.iter().find(|s| s.team == "A").unwrap();
+        let b = standings.iter().find(|s| s.team == "B").unwrap();
+
+        // With home-advantage in play, a home draw is a worse-than-expected result for the
+        // (favored) home team and a better-than-expected one for the away team.
+        assert!(a.elo_rating < 1500);
+        assert!(b.elo_rating > 1500);
+    }
+
+    #[test]
+    fn schedule() {
+        let mut a_beats_b = Game::new(1, "2018-08-11".parse().unwrap(), "A", "B");
+        a_beats_b.set_scores(2, 1);
+        let b_vs_a = Game::new(3, "2018-09-08".parse().unwrap(), "B", "A");
+        let a_vs_c = Game::new(2, "2018-08-25".parse().unwrap(), "A", "C");
+        let season = Season {
+            games: vec![a_beats_b, b_vs_a, a_vs_c],
+        };
+
+        assert_eq!(1, season.played().len());
+        let upcoming = season.upcoming();
+        assert_eq!(2, upcoming.len());
+        assert_eq!(2, upcoming[0].matchweek());
+        assert_eq!(3, upcoming[1].matchweek());
+        assert_eq!(Some(2), season.next_matchweek());
+    }
+
+    #[test]
+    fn stage_parse() {
+        assert_eq!(StageKind::Knockout, Stage::parse("Round of 16").unwrap().kind());
+        assert_eq!(StageKind::Knockout, Stage::parse("Quarterfinals").unwrap().kind());
+        assert_eq!(StageKind::Knockout, Stage::parse("Semifinals").unwrap().kind());
+        assert_eq!(StageKind::Knockout, Stage::parse("Quarter-finals").unwrap().kind());
+        assert_eq!(StageKind::Knockout, Stage::parse("Semi-finals").unwrap().kind());
+        assert_eq!(StageKind::Final, Stage::parse("Final").unwrap().kind());
+        assert_eq!(StageKind::Group, Stage::parse("Group A").unwrap().kind());
+        assert_eq!(
+            StageKind::Qualifying,
+            Stage::parse("Second Qualifying Round").unwrap().kind()
+        );
+        assert!(Stage::parse("Matchday 7").is_none());
+    }
+
+    #[test]
+    fn stage_persists_across_matchdays() {
+        let path = std::env::temp_dir().join("openfootball_stage_persists_across_matchdays.txt");
+        std::fs::write(
+            &path,
+            "# Euro Group Stage 2018/19\n\
+             Group A\n\
+             Matchday 1\n\
+             [Sat Aug/11]\n\
+             A 2-1 B\n\
+             Matchday 2\n\
+             [Sat Aug/18]\n\
+             B 1-2 A\n",
+        )
+        .unwrap();
+
+        let season = Season::from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let by_stage = season.by_stage();
+        let group_a = by_stage.get("Group A").unwrap();
+        assert_eq!(2, group_a.len());
+        for game in group_a {
+            assert_eq!(StageKind::Group, game.stage().unwrap().kind());
+        }
+    }
+
+    #[test]
+    fn game_status() {
+        let mut game = Game::new(1, "2018-08-11".parse().unwrap(), "Home", "Away");
+        assert_eq!(Status::Scheduled, game.status());
+        game.set_scores(1, 0);
+        assert_eq!(Status::Finished, game.status());
+    }
+
     #[test]
     fn standings() {
         let season = Season::from_path("tests/data/pl.txt").unwrap();
-        let standings = season.standings(1500, 32.).unwrap();
+        let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE).unwrap();
         let first = &standings[0];
         assert_eq!("Manchester United", first.team);
         assert_eq!(1516, first.elo_rating);
@@ -2,48 +2,174 @@ extern crate clap;
 extern crate csv;
 extern crate failure;
 extern crate openfootball;
+extern crate serde_json;
 
 use failure::Error;
+use openfootball::{Game, Odds, Season, Standing, DEFAULT_HOME_ADVANTAGE};
 
 fn main() -> Result<(), Error> {
     use clap::{App, Arg, SubCommand};
     use csv::Writer;
-    use openfootball::Season;
     use std::io;
 
     let infile = Arg::with_name("INFILE")
         .help("Sets the input openfootball text file")
         .required(true)
         .index(1);
+    let format = Arg::with_name("FORMAT")
+        .long("format")
+        .help("Sets the output format")
+        .takes_value(true)
+        .possible_values(&["csv", "table", "json"])
+        .default_value("csv");
     let matches = App::new("openfootball")
         .subcommand(
             SubCommand::with_name("standings")
-                .about("Prints standings as CSV data")
-                .arg(infile.clone()),
+                .about("Prints standings as CSV, JSON, or an aligned text table")
+                .arg(infile.clone())
+                .arg(format.clone()),
         )
         .subcommand(
             SubCommand::with_name("odds")
                 .about("Prints odds for a given matchweek")
-                .arg(infile)
+                .arg(infile.clone())
                 .arg(
                     Arg::with_name("MATCHWEEK")
                         .help("The matchweek")
                         .required(true)
                         .index(2),
-                ),
+                )
+                .arg(format.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("schedule")
+                .about("Prints a team's remaining fixtures")
+                .arg(infile)
+                .arg(
+                    Arg::with_name("TEAM")
+                        .help("The team")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(format),
         )
         .get_matches();
-    let mut writer = Writer::from_writer(io::stdout());
     if let Some(matches) = matches.subcommand_matches("standings") {
         let season = Season::from_path(matches.value_of("INFILE").unwrap())?;
-        for standing in season.standings(1500, 32.)? {
-            writer.serialize(standing)?;
+        let standings = season.standings(1500, 32., DEFAULT_HOME_ADVANTAGE)?;
+        match matches.value_of("FORMAT").unwrap() {
+            "json" => println!("{}", serde_json::to_string_pretty(&standings)?),
+            "table" => print_standings_table(&season)?,
+            _ => {
+                let mut writer = Writer::from_writer(io::stdout());
+                for standing in standings {
+                    writer.serialize(standing)?;
+                }
+            }
         }
     } else if let Some(matches) = matches.subcommand_matches("odds") {
         let season = Season::from_path(matches.value_of("INFILE").unwrap())?;
-        for odds in season.odds(1500, 32., matches.value_of("MATCHWEEK").unwrap().parse()?)? {
-            writer.serialize(odds)?;
+        let odds = season.odds(
+            1500,
+            32.,
+            DEFAULT_HOME_ADVANTAGE,
+            matches.value_of("MATCHWEEK").unwrap().parse()?,
+        )?;
+        match matches.value_of("FORMAT").unwrap() {
+            "json" => println!("{}", serde_json::to_string_pretty(&odds)?),
+            "table" => print_odds_table(&odds),
+            _ => {
+                let mut writer = Writer::from_writer(io::stdout());
+                for odds in odds {
+                    writer.serialize(odds)?;
+                }
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("schedule") {
+        let season = Season::from_path(matches.value_of("INFILE").unwrap())?;
+        let team = matches.value_of("TEAM").unwrap();
+        let fixtures: Vec<&Game> = season
+            .upcoming()
+            .into_iter()
+            .filter(|game| game.home() == team || game.away() == team)
+            .collect();
+        match matches.value_of("FORMAT").unwrap() {
+            "json" => println!("{}", serde_json::to_string_pretty(&fixtures)?),
+            "table" => print_schedule_table(&fixtures),
+            _ => {
+                let mut writer = Writer::from_writer(io::stdout());
+                for game in fixtures {
+                    writer.serialize(game)?;
+                }
+            }
         }
     }
     Ok(())
 }
+
+/// Prints the final league table as aligned, human-readable columns.
+fn print_standings_table(season: &Season) -> Result<(), Error> {
+    let matchweek = season
+        .games()
+        .iter()
+        .map(|game| game.matchweek())
+        .max()
+        .unwrap_or(0);
+    let table = season.table(matchweek, 1500, 32., DEFAULT_HOME_ADVANTAGE)?;
+    println!(
+        "{:>4} {:<25} {:>3} {:>3} {:>3} {:>3} {:>4} {:>4} {:>5} {:>4} {:>5}",
+        "Rank", "Club", "P", "W", "D", "L", "GF", "GA", "GD", "Pts", "Elo"
+    );
+    for standing in &table {
+        print_standing_row(standing);
+    }
+    Ok(())
+}
+
+fn print_standing_row(standing: &Standing) {
+    println!(
+        "{:>4} {:<25} {:>3} {:>3} {:>3} {:>3} {:>4} {:>4} {:>5} {:>4} {:>5}",
+        standing.position(),
+        standing.team(),
+        standing.played(),
+        standing.wins(),
+        standing.draws(),
+        standing.losses(),
+        standing.goals_for(),
+        standing.goals_against(),
+        standing.goal_difference(),
+        standing.points(),
+        standing.elo_rating(),
+    );
+}
+
+/// Prints a team's remaining fixtures as aligned, human-readable columns.
+fn print_schedule_table(fixtures: &[&Game]) {
+    println!("{:<12} {:>3} {:<25} {:<25}", "Date", "MW", "Home", "Away");
+    for game in fixtures {
+        println!(
+            "{:<12} {:>3} {:<25} {:<25}",
+            game.date(),
+            game.matchweek(),
+            game.home(),
+            game.away(),
+        );
+    }
+}
+
+/// Prints a list of odds as aligned, human-readable columns.
+fn print_odds_table(odds: &[Odds]) {
+    println!(
+        "{:<25} {:>8} {:<25} {:>8}",
+        "Home", "Home %", "Away", "Away %"
+    );
+    for o in odds {
+        println!(
+            "{:<25} {:>8.1} {:<25} {:>8.1}",
+            o.home(),
+            o.home_expected_score() * 100.,
+            o.away(),
+            o.away_expected_score() * 100.
+        );
+    }
+}